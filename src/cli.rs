@@ -2,7 +2,9 @@ use crate::github_fetcher::fetch_github_template;
 use crate::types::*;
 use anyhow;
 use clap::Parser;
-use inquire::{Select, Text};
+use inquire::validator::Validation;
+use inquire::{Confirm, Select, Text};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 /// Find a template name case-insensitively and return the actual template name
@@ -76,11 +78,16 @@ Examples:\n  - --integration cmake\n  - --integration make"
         long,
         env = "FUZZ_INIT_FUZZER",
         value_name = "FUZZER",
-        long_help = "🐞 Fuzzer engine to configure\n\n\
-All templates use LLVMFuzzerTestOneInput-style harnesses. This flag\ncustomizes the build setup.\n\n\
-Examples:\n  - --fuzzer libfuzzer\n  - --fuzzer afl"
+        value_delimiter = ',',
+        long_help = "🐞 Fuzzer engine(s) to configure (repeatable or comma-separated)\n\n\
+All templates use LLVMFuzzerTestOneInput-style harnesses. This flag customizes\n\
+the build setup; passing several engines lists each in the generated files\n\
+and prints build/run commands for each. The one shared harness is built per\n\
+engine through the fuzz helper's --lib-fuzzing-engine option; no separate\n\
+per-engine build targets are generated.\n\n\
+Examples:\n  - --fuzzer libfuzzer\n  - --fuzzer libfuzzer,afl,honggfuzz"
     )]
-    pub fuzzer: Option<String>,
+    pub fuzzer: Vec<String>,
 
     #[arg(
         long,
@@ -102,6 +109,105 @@ Usage:\n  - fuzz-init --minimal"
     #[arg(long)]
     pub minimal: bool,
 
+    #[arg(
+        long,
+        env = "FUZZ_INIT_SANITIZER",
+        value_name = "SANITIZER",
+        value_delimiter = ',',
+        long_help = "🧪 Sanitizers to build with (repeatable or comma-separated)\n\n\
+Propagates `-fsanitize=` flags into the standalone driver's makefile, and the\n\
+selection into the oss-fuzz project.yaml and the CI workflows. The bazel and\n\
+meson builds do not receive them. The template declares which sanitizers it\n\
+supports per language; MSan cannot be combined with ASan.\n\n\
+Examples:\n  - --sanitizer address\n  - --sanitizer undefined --sanitizer address"
+    )]
+    pub sanitizer: Vec<String>,
+
+    #[arg(
+        long,
+        env = "FUZZ_INIT_CI",
+        value_name = "PROVIDER",
+        default_value = "none",
+        value_parser = ["github", "gitlab", "none"],
+        long_help = "⚙️ Continuous fuzzing CI workflows (ClusterFuzzLite)\n\n\
+Emits `.clusterfuzzlite/` config plus a provider-specific workflow that runs\n\
+code-change (PR), batch, and corpus-pruning fuzzing against the oss-fuzz\n\
+`build.sh`/`Dockerfile`.\n\n\
+Examples:\n  - --ci github\n  - --ci gitlab"
+    )]
+    pub ci: String,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        long_help = "🌳 Integrate into an existing source tree at PATH\n\n\
+Instead of creating a fresh `<project_name>/` directory, the `fuzz/` harness\n\
+and build glue are dropped into this repository. Its path is passed to the\n\
+templates as `project_src`; the bundled templates do not reference it.\n\
+Implies --minimal.\n\n\
+Example:\n  - --project-src ../libfoo"
+    )]
+    pub project_src: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        long_help = "📂 Directory to place the generated build integration\n\n\
+Defaults to the --project-src tree. Use this when the fuzz glue should live in\n\
+a subdirectory separate from the code under test.\n\n\
+Example:\n  - --project-src ../libfoo --integration-path ../libfoo/fuzzing"
+    )]
+    pub integration_path: Option<PathBuf>,
+
+    #[arg(
+        long,
+        env = "FUZZ_INIT_FAVORITE",
+        value_name = "NAME",
+        long_help = "⭐ Apply a named favorite preset from the user config\n\n\
+Expands `[favorites.<NAME>]` from `fuzz-init.toml` as if its template source\n\
+and options had been passed on the command line. Explicit flags still win.\n\n\
+Example:\n  - --favorite myorg"
+    )]
+    pub favorite: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "KEY=VALUE",
+        long_help = "🧩 Supply a template-declared variable (repeatable)\n\n\
+Provides a value for a variable declared in the template's `[variables]`\n\
+section, bypassing the interactive prompt. Useful for scripting.\n\n\
+Examples:\n  - --define author=\"Jane Doe\"\n  - --define license=MIT --define with_ci=true"
+    )]
+    pub define: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "DIR",
+        long_help = "🌱 Seed corpus directory to copy into the generated corpus\n\n\
+Files in DIR are copied into `fuzz/corpus/<target>/` as starting inputs.\n\n\
+Example:\n  - --seed-corpus ./samples"
+    )]
+    pub seed_corpus: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        long_help = "📖 Dictionary file to seed the generated `<target>.dict`\n\n\
+Its contents are copied into the emitted dictionary stub, which the run\n\
+script passes to the engine via `-dict=`.\n\n\
+Example:\n  - --dictionary ./http.dict"
+    )]
+    pub dictionary: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "KEY=VALUE",
+        long_help = "⚙️ libFuzzer runtime option baked into the run script (repeatable)\n\n\
+Accepts the usual `key=value` engine options. Unknown keys warn but are kept.\n\n\
+Examples:\n  - --fuzzer-option max_len=4096\n  - --fuzzer-option timeout=25 --fuzzer-option close_fd_mask=3"
+    )]
+    pub fuzzer_option: Vec<String>,
+
     #[arg(
         long,
         hide = false,
@@ -150,6 +256,48 @@ Example:\n\
     pub dev_output: Option<String>,
 }
 
+/// Seed unset CLI flags from the user config so `determine_template_source`,
+/// `select_fuzzer`, and `select_integration` see favorite/default values as if
+/// they had been typed on the command line. Explicit flags always take
+/// precedence: a named `--favorite` fills whatever the user did not pass, and
+/// the plain `[defaults]` table fills whatever still remains.
+pub fn apply_user_config(args: &mut Args, config: &UserConfig) -> anyhow::Result<()> {
+    if let Some(name) = &args.favorite {
+        let favorite = config.favorites.get(name).ok_or_else(|| {
+            anyhow::anyhow!("Unknown favorite '{}' (not defined in fuzz-init.toml)", name)
+        })?;
+        // --language and --template are mutually exclusive, so an explicit
+        // one also blocks the favorite from filling the other.
+        if args.template.is_none() && args.language.is_none() {
+            args.template = favorite.template.clone();
+        }
+        if args.language.is_none() && args.template.is_none() {
+            args.language = favorite.language.clone();
+        }
+        if args.fuzzer.is_empty() {
+            args.fuzzer = favorite.fuzzer.clone();
+        }
+        if args.integration.is_none() {
+            args.integration = favorite.integration.clone();
+        }
+        args.minimal = args.minimal || favorite.minimal;
+    }
+
+    let defaults = &config.defaults;
+    if args.language.is_none() && args.template.is_none() {
+        args.language = defaults.language.clone();
+    }
+    if args.fuzzer.is_empty() {
+        args.fuzzer = defaults.fuzzer.clone();
+    }
+    if args.integration.is_none() {
+        args.integration = defaults.integration.clone();
+    }
+    args.minimal = args.minimal || defaults.minimal;
+
+    Ok(())
+}
+
 pub fn get_project_name(args: &Args) -> anyhow::Result<String> {
     match args.project.as_ref().or(args.project_name_pos.as_ref()) {
         Some(name) => Ok(name.clone()),
@@ -293,77 +441,41 @@ pub async fn get_template_name(
     }
 }
 
-pub fn select_fuzzer(args: &Args, metadata: Option<&TemplateMetadata>) -> anyhow::Result<String> {
-    if let Some(fuzzer) = &args.fuzzer {
-        // Validate fuzzer type against template metadata if available
-        if let Some(metadata) = metadata {
-            if let Some(fuzzers) = &metadata.fuzzers {
-                if !fuzzers.supported.contains(fuzzer) {
-                    anyhow::bail!(
-                        "Fuzzer '{}' not supported by this template. Supported: {}",
-                        fuzzer,
-                        fuzzers.supported.join(", ")
-                    );
-                }
-            }
-        }
-        Ok(fuzzer.clone())
-    } else {
-        // Get default from metadata or prompt user
-        if let Some(metadata) = metadata {
-            if let Some(fuzzers) = &metadata.fuzzers {
-                if fuzzers.supported.len() == 1 {
-                    // Only one option, use it
-                    Ok(fuzzers.supported[0].clone())
-                } else {
-                    // Multiple options, prompt user
-                    let options: Vec<String> = fuzzers
-                        .options
-                        .iter()
-                        .map(|opt| format!("{} - {}", opt.display_name, opt.description))
-                        .collect();
-                    let selected = Select::new("Choose a fuzzer", options).prompt()?;
-                    let fuzzer_name = selected.split(" - ").next().unwrap();
-
-                    // Find the actual fuzzer name from display name
-                    for option in &fuzzers.options {
-                        if option.display_name == fuzzer_name {
-                            return Ok(option.name.clone());
-                        }
-                    }
-                    Ok(fuzzers.default.clone())
-                }
-            } else {
-                Ok("libfuzzer".to_string()) // Default fallback
-            }
-        } else {
-            Ok("libfuzzer".to_string()) // Default fallback
-        }
-    }
+pub fn select_fuzzer(args: &Args, metadata: Option<&TemplateMetadata>) -> anyhow::Result<Vec<String>> {
+    Ok(select_fuzzer_with_tracking(args, metadata)?.0)
 }
 
-pub fn select_fuzzer_with_tracking(args: &Args, metadata: Option<&TemplateMetadata>) -> anyhow::Result<(String, bool)> {
-    if let Some(fuzzer) = &args.fuzzer {
-        // Validate fuzzer type against template metadata if available
+/// Resolve the fuzzer engine selection, which may name several engines so the
+/// shared harness is built and run under each.
+///
+/// Every engine passed via `--fuzzer` is validated against the template's
+/// `supported` list. When the flag is omitted, a single engine is resolved from
+/// metadata (using the sole supported option, or prompting the user otherwise).
+/// The boolean reports whether the selection came from an interactive prompt.
+pub fn select_fuzzer_with_tracking(args: &Args, metadata: Option<&TemplateMetadata>) -> anyhow::Result<(Vec<String>, bool)> {
+    if !args.fuzzer.is_empty() {
+        // Validate each requested engine against template metadata if available
         if let Some(metadata) = metadata {
             if let Some(fuzzers) = &metadata.fuzzers {
-                if !fuzzers.supported.contains(fuzzer) {
-                    anyhow::bail!(
-                        "Fuzzer '{}' not supported by this template. Supported: {}",
-                        fuzzer,
-                        fuzzers.supported.join(", ")
-                    );
+                for fuzzer in &args.fuzzer {
+                    if !fuzzers.supported.contains(fuzzer) {
+                        anyhow::bail!(
+                            "Fuzzer '{}' not supported by this template. Supported: {}",
+                            fuzzer,
+                            fuzzers.supported.join(", ")
+                        );
+                    }
                 }
             }
         }
-        Ok((fuzzer.clone(), false)) // false = not prompted
+        Ok((args.fuzzer.clone(), false)) // false = not prompted
     } else {
         // Get default from metadata or prompt user
         if let Some(metadata) = metadata {
             if let Some(fuzzers) = &metadata.fuzzers {
                 if fuzzers.supported.len() == 1 {
                     // Only one option, use it (not considered prompted)
-                    Ok((fuzzers.supported[0].clone(), false))
+                    Ok((vec![fuzzers.supported[0].clone()], false))
                 } else {
                     // Multiple options, prompt user
                     let options: Vec<String> = fuzzers
@@ -377,16 +489,16 @@ pub fn select_fuzzer_with_tracking(args: &Args, metadata: Option<&TemplateMetada
                     // Find the actual fuzzer name from display name
                     for option in &fuzzers.options {
                         if option.display_name == fuzzer_name {
-                            return Ok((option.name.clone(), true)); // true = prompted
+                            return Ok((vec![option.name.clone()], true)); // true = prompted
                         }
                     }
-                    Ok((fuzzers.default.clone(), true)) // true = prompted
+                    Ok((vec![fuzzers.default.clone()], true)) // true = prompted
                 }
             } else {
-                Ok(("libfuzzer".to_string(), false)) // Default fallback
+                Ok((vec!["libfuzzer".to_string()], false)) // Default fallback
             }
         } else {
-            Ok(("libfuzzer".to_string(), false)) // Default fallback
+            Ok((vec!["libfuzzer".to_string()], false)) // Default fallback
         }
     }
 }
@@ -505,22 +617,248 @@ pub fn select_integration_with_tracking(
     }
 }
 
+/// Resolve and validate the sanitizer selection, mirroring `select_fuzzer`.
+///
+/// When `--sanitizer` is omitted, the template's declared default is used
+/// (falling back to AddressSanitizer). Each selected sanitizer must appear in
+/// the template's `supported` list, and the mutually-exclusive MSan/ASan
+/// combination is rejected before any files are generated.
+pub fn select_sanitizers(
+    args: &Args,
+    metadata: Option<&TemplateMetadata>,
+) -> anyhow::Result<Vec<String>> {
+    let config = metadata.and_then(|m| m.sanitizers.as_ref());
+
+    let chosen: Vec<String> = if !args.sanitizer.is_empty() {
+        args.sanitizer.clone()
+    } else if let Some(config) = config {
+        config.default.clone()
+    } else {
+        vec!["address".to_string()]
+    };
+
+    if let Some(config) = config {
+        for sanitizer in &chosen {
+            if !config.supported.contains(sanitizer) {
+                anyhow::bail!(
+                    "Sanitizer '{}' not supported by this template. Supported: {}",
+                    sanitizer,
+                    config.supported.join(", ")
+                );
+            }
+        }
+    }
+
+    if chosen.iter().any(|s| s == "memory") && chosen.iter().any(|s| s == "address") {
+        anyhow::bail!(
+            "MemorySanitizer (memory) cannot be combined with AddressSanitizer (address)"
+        );
+    }
+
+    Ok(chosen)
+}
+
+/// Collect values for the extra substitution variables a template declares in
+/// its `[variables]` section.
+///
+/// Each variable is resolved either from a `--define KEY=VALUE` flag or, when no
+/// flag supplies it, by prompting with `inquire`: `Select` when the variable
+/// lists `choices`, a yes/no `Confirm` for `type = "bool"`, and otherwise a
+/// `Text` prompt whose value must match the variable's compiled `regex` (the
+/// prompt repeats until it does). Variables resolved by prompting are appended
+/// to `prompted.defines` so the reproducible CLI hint can echo the matching
+/// `--define` flags. A variable that declares a `default` resolves to it
+/// without prompting when not supplied, so fully-specified and scripted runs
+/// never block; only variables with no default are prompted for.
+pub fn collect_template_variables(
+    args: &Args,
+    metadata: Option<&TemplateMetadata>,
+    prompted: &mut PromptedValues,
+) -> anyhow::Result<HashMap<String, serde_json::Value>> {
+    let mut values = HashMap::new();
+
+    let metadata = match metadata {
+        Some(metadata) => metadata,
+        None => return Ok(values),
+    };
+
+    // Index the --define flags by key for lookup.
+    let mut defined = HashMap::new();
+    for entry in &args.define {
+        let (key, value) = entry.split_once('=').ok_or_else(|| {
+            anyhow::anyhow!("Invalid --define '{}', expected KEY=VALUE", entry)
+        })?;
+        defined.insert(key.to_string(), value.to_string());
+    }
+
+    // Resolve variables in name order so prompts and the CLI hint are stable.
+    let mut names: Vec<&String> = metadata.variables.keys().collect();
+    names.sort();
+
+    for name in names {
+        let config = &metadata.variables[name];
+        let is_bool = config.var_type.as_deref() == Some("bool");
+        let message = config.prompt.clone().unwrap_or_else(|| name.clone());
+
+        let value = if let Some(raw) = defined.get(name) {
+            // Supplied on the command line - no prompt, but still subject to the
+            // same regex the interactive path enforces.
+            if is_bool {
+                serde_json::Value::Bool(parse_bool(raw))
+            } else {
+                if let Some(pattern) = &config.regex {
+                    let re = regex::Regex::new(pattern).map_err(|e| {
+                        anyhow::anyhow!("Invalid regex for variable '{}': {}", name, e)
+                    })?;
+                    if !re.is_match(raw) {
+                        anyhow::bail!(
+                            "Value '{}' for '{}' does not match /{}/",
+                            raw,
+                            name,
+                            pattern
+                        );
+                    }
+                }
+                serde_json::Value::String(raw.clone())
+            }
+        } else if let Some(default) = &config.default {
+            // A declared default resolves to its value whenever the variable is
+            // not supplied via --define, so fully-specified and scripted runs
+            // never block on a prompt (and never error under a non-TTY). The
+            // default is held to the same regex the --define path enforces.
+            if is_bool {
+                serde_json::Value::Bool(parse_bool(default))
+            } else {
+                if let Some(pattern) = &config.regex {
+                    let re = regex::Regex::new(pattern).map_err(|e| {
+                        anyhow::anyhow!("Invalid regex for variable '{}': {}", name, e)
+                    })?;
+                    if !re.is_match(default) {
+                        anyhow::bail!(
+                            "Default '{}' for '{}' does not match /{}/",
+                            default,
+                            name,
+                            pattern
+                        );
+                    }
+                }
+                serde_json::Value::String(default.clone())
+            }
+        } else if is_bool {
+            let answer = Confirm::new(&message).with_default(false).prompt()?;
+            prompted.defines.push(format!("{}={}", name, answer));
+            serde_json::Value::Bool(answer)
+        } else if let Some(choices) = &config.choices {
+            let answer = Select::new(&message, choices.clone()).prompt()?;
+            prompted.defines.push(format!("{}={}", name, answer));
+            serde_json::Value::String(answer)
+        } else {
+            // No default declared: prompt, enforcing the regex if present.
+            let mut prompt = Text::new(&message);
+            if let Some(pattern) = &config.regex {
+                let re = regex::Regex::new(pattern).map_err(|e| {
+                    anyhow::anyhow!("Invalid regex for variable '{}': {}", name, e)
+                })?;
+                let message = format!("must match /{pattern}/");
+                prompt = prompt.with_validator(move |input: &str| {
+                    if re.is_match(input) {
+                        Ok(Validation::Valid)
+                    } else {
+                        Ok(Validation::Invalid(message.clone().into()))
+                    }
+                });
+            }
+            let answer = prompt.prompt()?;
+            prompted.defines.push(format!("{}={}", name, answer));
+            serde_json::Value::String(answer)
+        };
+
+        values.insert(name.clone(), value);
+    }
+
+    Ok(values)
+}
+
+/// Recognized libFuzzer runtime option keys. Unknown keys are still honored
+/// (newer engines add options) but warned about so typos surface early.
+const KNOWN_FUZZER_OPTIONS: &[&str] = &[
+    "max_len",
+    "timeout",
+    "rss_limit_mb",
+    "malloc_limit_mb",
+    "max_total_time",
+    "runs",
+    "len_control",
+    "close_fd_mask",
+    "dict",
+    "jobs",
+    "workers",
+    "seed",
+    "only_ascii",
+    "print_final_stats",
+    "detect_leaks",
+    "use_value_profile",
+    "artifact_prefix",
+    "timeout_exitcode",
+    "error_exitcode",
+    "fork",
+    "ignore_crashes",
+];
+
+/// Validate and normalize the repeatable `--fuzzer-option key=value` flags into
+/// `key=value` strings for the renderer to bake into the run script. Each entry
+/// must contain a `=`; unrecognized keys warn but are retained.
+pub fn parse_fuzzer_options(args: &Args) -> anyhow::Result<Vec<String>> {
+    let mut options = Vec::new();
+    for entry in &args.fuzzer_option {
+        let (key, value) = entry.split_once('=').ok_or_else(|| {
+            anyhow::anyhow!("Invalid --fuzzer-option '{}', expected key=value", entry)
+        })?;
+        if key.is_empty() || value.is_empty() {
+            anyhow::bail!("Invalid --fuzzer-option '{}', expected key=value", entry);
+        }
+        if !KNOWN_FUZZER_OPTIONS.contains(&key) {
+            eprintln!("⚠️  Unknown fuzzer option '{key}'; passing it through anyway");
+        }
+        options.push(format!("{key}={value}"));
+    }
+    Ok(options)
+}
+
+/// Interpret a string as a boolean for `--define`/defaults, treating the usual
+/// truthy spellings as `true` and everything else as `false`.
+fn parse_bool(value: &str) -> bool {
+    matches!(value.trim().to_lowercase().as_str(), "true" | "1" | "yes" | "y")
+}
+
 pub fn determine_minimal_mode(args: &Args, _template_source: &TemplateSource) -> bool {
-    args.minimal
+    // Integrating into an external tree only drops the fuzz/ glue, never the
+    // bundled example project, so it always implies minimal mode.
+    args.minimal || args.project_src.is_some()
 }
 
 pub fn print_next_steps(
     project_name: &str, 
     minimal_mode: bool, 
-    prompted_values: &crate::types::PromptedValues, 
-    template_source: &TemplateSource, 
-    fuzzer: &str,
-    integration: &str
+    prompted_values: &crate::types::PromptedValues,
+    template_source: &TemplateSource,
+    language: &str,
+    fuzzers: &[String],
+    integration: &str,
+    sanitizers: &[String],
+    target: &str,
+    fuzzer_options: &[String],
+    project_src: Option<&std::path::Path>,
+    integration_path: Option<&std::path::Path>,
 ) {
     println!();
     println!("🚀 Next steps:");
     println!("==============");
-    if !minimal_mode {
+    if let Some(src) = project_src {
+        // External integration: the glue lives in the user's own tree.
+        println!("1. cd {}", src.display());
+        println!("2. Read fuzz/INTEGRATION.md");
+    } else if !minimal_mode {
         println!("1. cd {}", project_name);
         println!("2. Read TUTORIAL.md");
     } else {
@@ -536,8 +874,28 @@ pub fn print_next_steps(
     println!("   - fuzz/INTEGRATION.md  - Integration guide for existing projects");
     println!("   - fuzz/README.md       - Quick reference for fuzzing commands");
 
-    // Generate CLI hint if any values were prompted
-    if prompted_values.project_name || prompted_values.language || prompted_values.fuzzer || prompted_values.integration {
+    println!();
+    println!("🌱 Corpus & dictionary:");
+    println!("   - fuzz/corpus/{target}/  - drop seed inputs here to bootstrap coverage");
+    println!("   - fuzz/{target}.dict     - add tokens to this dictionary to reach deeper paths");
+
+    println!();
+    println!("🐞 Build & run per engine:");
+    // Rust ships a shell helper; C/C++ ship the Python one.
+    let helper = if language.eq_ignore_ascii_case("rust") {
+        "fuzz.sh"
+    } else {
+        "fuzz.py"
+    };
+    for fuzzer in fuzzers {
+        // The engine option belongs to the helper itself, before the subcommand.
+        println!("   - ./fuzz/{helper} --lib-fuzzing-engine {fuzzer} build {target}");
+        println!("     ./fuzz/{helper} --lib-fuzzing-engine {fuzzer} run {target}");
+    }
+
+    // Generate CLI hint if any values were prompted (always for external
+    // integrations, so the exact reproduction is recorded).
+    if prompted_values.project_name || prompted_values.language || prompted_values.fuzzer || prompted_values.integration || !prompted_values.defines.is_empty() || project_src.is_some() {
         println!();
         println!("💡 CLI Hint:");
         println!("============");
@@ -554,14 +912,37 @@ pub fn print_next_steps(
             command.push_str(&format!(" --language {}", language));
         }
         
-        // Add other parameters
-        command.push_str(&format!(" --fuzzer {}", fuzzer));
+        // Add other parameters (the full engine list, comma-separated)
+        command.push_str(&format!(" --fuzzer {}", fuzzers.join(",")));
         command.push_str(&format!(" --integration {}", integration));
-        
+
+        // Echo the sanitizer selection used
+        for sanitizer in sanitizers {
+            command.push_str(&format!(" --sanitizer {}", sanitizer));
+        }
+
+        // Echo any template variables that were answered interactively.
+        for define in &prompted_values.defines {
+            command.push_str(&format!(" --define {}", define));
+        }
+
+        // Echo the external-integration paths so the run can be reproduced.
+        if let Some(src) = project_src {
+            command.push_str(&format!(" --project-src {}", src.display()));
+        }
+        if let Some(path) = integration_path {
+            command.push_str(&format!(" --integration-path {}", path.display()));
+        }
+
+        // Echo the libFuzzer runtime options baked into the run script.
+        for option in fuzzer_options {
+            command.push_str(&format!(" --fuzzer-option {}", option));
+        }
+
         if minimal_mode {
             command.push_str(" --minimal");
         }
-        
+
         println!("  {}", command);
         println!();
     }
@@ -344,11 +344,26 @@ async fn generate_test_project(
     // Set up handlebars and data for template processing
     let handlebars = setup_handlebars();
 
+    let name = project_dir.file_name().unwrap().to_str().unwrap();
+    // Mirror the built-in keys from the real generation path in `main.rs` so the
+    // validation harness renders the same template branches (engine list,
+    // sanitizer flags) a real scaffold would. `ci` stays "none" here,
+    // so the CI-gated workflow files are intentionally left out of this pass.
     let data = json!({
-        "project_name": project_dir.file_name().unwrap().to_str().unwrap(),
-        "target_name": project_dir.file_name().unwrap().to_str().unwrap(),
-        //"default_fuzzer": config.fuzzer,
+        "project_name": name,
+        "target_name": name,
+        "language": config.language,
         "integration": config.integration,
+        "fuzzer": "libfuzzer",
+        "fuzzers": ["libfuzzer"],
+        "sanitizers": ["address"],
+        "sanitizer_flags": "-fsanitize=address",
+        "corpus": format!("corpus/{name}"),
+        "ci": "none",
+        "fuzzer_options": Vec::<String>::new(),
+        "homepage": "https://example.com",
+        "project_src": serde_json::Value::Null,
+        "external": false,
         "minimal": config.minimal
     });
 
@@ -14,7 +14,7 @@ use template_processor::*;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
+    let mut args = Args::parse();
 
     // Check if documentation generation was requested
     if args.generate_docs {
@@ -27,6 +27,9 @@ async fn main() -> anyhow::Result<()> {
         return dev_mode::run_dev_mode(&args).await;
     }
 
+    // Apply favorites and defaults from the user config before reading any flag.
+    apply_user_config(&mut args, &load_user_config())?;
+
     // Get available templates
     let available_templates = get_available_templates()?;
     if available_templates.is_empty() {
@@ -58,7 +61,30 @@ async fn main() -> anyhow::Result<()> {
         select_integration_with_tracking(&args, metadata.as_ref())?;
     prompted_values.integration = prompted_integration;
 
-    let minimal_mode = args.minimal;
+    let (fuzzers, prompted_fuzzer) = select_fuzzer_with_tracking(&args, metadata.as_ref())?;
+    prompted_values.fuzzer = prompted_fuzzer;
+    // The first engine is the primary one templates substitute for `{{fuzzer}}`;
+    // the full list is rendered as `fuzzers` and drives the per-engine build/run
+    // commands printed by `print_next_steps`.
+    let fuzzer = fuzzers.first().cloned().unwrap_or_else(|| "libfuzzer".to_string());
+
+    let sanitizers = select_sanitizers(&args, metadata.as_ref())?;
+
+    let template_variables =
+        collect_template_variables(&args, metadata.as_ref(), &mut prompted_values)?;
+
+    let fuzzer_options = parse_fuzzer_options(&args)?;
+
+    let minimal_mode = determine_minimal_mode(&args, &template_source);
+
+    // External integration drops the glue into an existing tree rather than a
+    // fresh `<project_name>/` directory.
+    let external_src = args.project_src.clone();
+    let output_dir: std::path::PathBuf = match (&args.integration_path, &args.project_src) {
+        (Some(path), _) => path.clone(),
+        (None, Some(src)) => src.clone(),
+        (None, None) => std::path::PathBuf::from(&project_name),
+    };
 
     // Setup Handlebars with helpers
     let handlebars = setup_handlebars();
@@ -70,15 +96,39 @@ async fn main() -> anyhow::Result<()> {
         .to_string_lossy()
         .to_string();
 
-    let data = json!({
+    let mut data = json!({
         "project_name": project_name,
         "target_name": project_basename, // Use base name only for template filenames
+        "language": template_name,
         "integration": integration_type,
+        "fuzzer": fuzzer,
+        "fuzzers": fuzzers.clone(),
+        "sanitizers": sanitizers,
+        "sanitizer_flags": if sanitizers.is_empty() {
+            String::new()
+        } else {
+            format!("-fsanitize={}", sanitizers.join(","))
+        },
+        "corpus": format!("corpus/{project_basename}"),
+        "ci": args.ci,
+        "fuzzer_options": fuzzer_options,
+        // Path to the external source tree being integrated, so templates can
+        // wire includes/linkage back to it instead of bundled example code.
+        "project_src": external_src.as_ref().map(|p| p.to_string_lossy().to_string()),
+        "external": external_src.is_some(),
         "minimal": minimal_mode
     });
 
+    // Fold in the template-declared variables so the renderer can substitute
+    // them alongside the built-in keys.
+    if let Some(object) = data.as_object_mut() {
+        for (key, value) in template_variables {
+            object.insert(key, value);
+        }
+    }
+
     // Generate project - handle nested paths properly
-    let out_path = Path::new(&project_name);
+    let out_path = output_dir.as_path();
 
     // Create parent directories if they don't exist
     if let Some(parent) = out_path.parent() {
@@ -86,7 +136,7 @@ async fn main() -> anyhow::Result<()> {
             std::fs::create_dir_all(parent).map_err(|e| {
                 anyhow::anyhow!(
                     "Failed to create parent directories for '{}': {}",
-                    project_name,
+                    out_path.display(),
                     e
                 )
             })?;
@@ -114,6 +164,15 @@ async fn main() -> anyhow::Result<()> {
         )?;
     }
 
+    // Scaffold the seed corpus directory and dictionary stub for the target.
+    scaffold_corpus_and_dictionary(
+        out_path,
+        &project_basename,
+        args.seed_corpus.as_deref(),
+        args.dictionary.as_deref(),
+        &fuzzer_options,
+    )?;
+
     // Success message with next steps
     println!("Project '{project_name}' created with {template_name} template!");
 
@@ -125,7 +184,14 @@ async fn main() -> anyhow::Result<()> {
         minimal_mode,
         &prompted_values,
         &template_source,
+        &template_name,
+        &fuzzers,
         &integration_type,
+        &sanitizers,
+        &project_basename,
+        &fuzzer_options,
+        external_src.as_deref(),
+        args.integration_path.as_deref(),
     );
 
     Ok(())
@@ -1,6 +1,6 @@
 use crate::types::*;
 use handlebars::Handlebars;
-use std::{fs, path::Path};
+use std::{fs, path::Path, path::PathBuf};
 
 // Conditional template loading based on build mode
 #[cfg(not(debug_assertions))]
@@ -12,6 +12,64 @@ static TEMPLATES_DIR: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/src/templates"
 #[cfg(debug_assertions)]
 static TEMPLATES_PATH: &str = "src/templates";
 
+#[cfg(not(debug_assertions))]
+static PARTIALS_DIR: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/src/partials");
+
+#[cfg(debug_assertions)]
+static PARTIALS_PATH: &str = "src/partials";
+
+/// Load the user config file, searching upward from the current directory and
+/// then the platform config directory. A missing or malformed file yields the
+/// default (empty) config rather than an error.
+pub fn load_user_config() -> UserConfig {
+    let parse = |path: &Path| -> Option<UserConfig> {
+        let content = fs::read_to_string(path).ok()?;
+        toml::from_str(&content).ok()
+    };
+
+    if let Ok(cwd) = std::env::current_dir() {
+        let mut dir = Some(cwd.as_path());
+        while let Some(d) = dir {
+            let candidate = d.join("fuzz-init.toml");
+            if candidate.exists() {
+                if let Some(config) = parse(&candidate) {
+                    return config;
+                }
+            }
+            dir = d.parent();
+        }
+    }
+
+    if let Some(config_dir) = dirs::config_dir() {
+        let candidate = config_dir.join("fuzz-init").join("fuzz-init.toml");
+        if candidate.exists() {
+            if let Some(config) = parse(&candidate) {
+                return config;
+            }
+        }
+    }
+
+    UserConfig::default()
+}
+
+/// Additional template roots declared in the user config, in declaration order.
+fn external_template_roots() -> Vec<PathBuf> {
+    load_user_config().template_dirs
+}
+
+/// Resolve a template name against the configured external roots. Later roots
+/// win, so the last matching root is returned.
+fn resolve_external_template(name: &str) -> Option<PathBuf> {
+    let mut resolved = None;
+    for root in external_template_roots() {
+        let candidate = root.join(name);
+        if candidate.is_dir() {
+            resolved = Some(candidate);
+        }
+    }
+    resolved
+}
+
 pub fn get_available_templates() -> anyhow::Result<Vec<String>> {
     let mut templates = Vec::new();
 
@@ -47,11 +105,33 @@ pub fn get_available_templates() -> anyhow::Result<Vec<String>> {
         }
     }
 
+    // Union in templates from configured external roots (names only; later
+    // roots override earlier ones at resolution time).
+    for root in external_template_roots() {
+        if root.exists() {
+            for entry in fs::read_dir(&root)? {
+                let entry = entry?;
+                if entry.file_type()?.is_dir() {
+                    if let Some(name) = entry.file_name().to_str() {
+                        if !templates.iter().any(|t| t == name) {
+                            templates.push(name.to_string());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     templates.sort();
     Ok(templates)
 }
 
 pub fn load_template_metadata(template_name: &str) -> anyhow::Result<Option<TemplateMetadata>> {
+    // Configured external roots take precedence over the built-in set.
+    if let Some(path) = resolve_external_template(template_name) {
+        return load_template_metadata_from_path(&path);
+    }
+
     #[cfg(not(debug_assertions))]
     {
         // Release mode: use embedded templates
@@ -92,14 +172,112 @@ pub fn load_template_metadata(template_name: &str) -> anyhow::Result<Option<Temp
 }
 
 pub fn setup_handlebars() -> Handlebars<'static> {
-    let handlebars = Handlebars::new();
+    let mut handlebars = Handlebars::new();
 
     // Handlebars 6.x has built-in comparison helpers: eq, ne, gt, gte, lt, lte
     // and logical helpers: and, or, not - no need to register custom ones
 
+    // Register shared snippets from the top-level `partials/` tree so any
+    // template can pull them in with `{{> license_header}}` or
+    // `{{> ci/github_actions}}`. A missing partials tree is not an error.
+    if let Err(e) = register_partials(&mut handlebars) {
+        eprintln!("Warning: failed to register partials: {e}");
+    }
+
     handlebars
 }
 
+/// Scan the embedded (release) or on-disk (debug) `partials/` tree and register
+/// every file as a named partial. Nested files keep their path as the name,
+/// minus the file extension (`ci/github_actions.hbs` -> `ci/github_actions`).
+fn register_partials(handlebars: &mut Handlebars) -> anyhow::Result<()> {
+    #[cfg(not(debug_assertions))]
+    {
+        register_embedded_partials(handlebars, &PARTIALS_DIR, "")?;
+    }
+
+    #[cfg(debug_assertions)]
+    {
+        let base = Path::new(PARTIALS_PATH);
+        if base.exists() {
+            register_filesystem_partials(handlebars, base, "")?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(not(debug_assertions))]
+fn register_embedded_partials(
+    handlebars: &mut Handlebars,
+    dir: &include_dir::Dir,
+    prefix: &str,
+) -> anyhow::Result<()> {
+    for file in dir.files() {
+        if let Some(content) = file.contents_utf8() {
+            let stem = file
+                .path()
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or_default();
+            let name = if prefix.is_empty() {
+                stem.to_string()
+            } else {
+                format!("{prefix}/{stem}")
+            };
+            handlebars.register_partial(&name, content)?;
+        }
+    }
+
+    for subdir in dir.dirs() {
+        let subdir_name = subdir.path().file_name().unwrap().to_str().unwrap();
+        let new_prefix = if prefix.is_empty() {
+            subdir_name.to_string()
+        } else {
+            format!("{prefix}/{subdir_name}")
+        };
+        register_embedded_partials(handlebars, subdir, &new_prefix)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(debug_assertions)]
+fn register_filesystem_partials(
+    handlebars: &mut Handlebars,
+    dir: &Path,
+    prefix: &str,
+) -> anyhow::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+
+        if file_type.is_dir() {
+            let new_prefix = if prefix.is_empty() {
+                file_name
+            } else {
+                format!("{prefix}/{file_name}")
+            };
+            register_filesystem_partials(handlebars, &entry.path(), &new_prefix)?;
+        } else if file_type.is_file() {
+            let content = fs::read_to_string(entry.path())?;
+            let stem = Path::new(&file_name)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or(&file_name);
+            let name = if prefix.is_empty() {
+                stem.to_string()
+            } else {
+                format!("{prefix}/{stem}")
+            };
+            handlebars.register_partial(&name, content)?;
+        }
+    }
+
+    Ok(())
+}
+
 pub fn load_template_metadata_from_path(
     template_path: &Path,
 ) -> anyhow::Result<Option<TemplateMetadata>> {
@@ -120,6 +298,13 @@ pub fn process_template_directory(
     data: &serde_json::Value,
     metadata: Option<&TemplateMetadata>,
 ) -> anyhow::Result<()> {
+    // Configured external roots take precedence over the built-in set.
+    if let Some(path) = resolve_external_template(template_name) {
+        return process_filesystem_template_directory(
+            &path, output_dir, handlebars, data, metadata,
+        );
+    }
+
     #[cfg(not(debug_assertions))]
     {
         // Release mode: use embedded templates
@@ -218,8 +403,8 @@ fn process_embedded_template_directory(
             continue;
         };
 
-        // Write the processed content
-        fs::write(&output_path, content)?;
+        // Write the processed content, honoring any merge strategy
+        write_with_merge(&output_path, &content, file_config)?;
 
         // Set executable permissions if needed
         if file_config.map_or(false, |fc| fc.is_executable()) {
@@ -254,6 +439,11 @@ fn process_embedded_template_directory(
                     continue;
                 }
             }
+
+            // Short-circuit subtrees that no include glob can ever match.
+            if !directory_prefix_can_match(&metadata.file_conventions, &current_relative_path) {
+                continue;
+            }
         }
 
         // Template the directory name if needed
@@ -273,6 +463,130 @@ fn process_embedded_template_directory(
     Ok(())
 }
 
+/// Write rendered content to `output_path`, honoring the file's merge strategy
+/// when the path already exists. `append`/`prepend` concatenate with a
+/// separator (default newline); `skip_if_exists` leaves the file untouched.
+fn write_with_merge(
+    output_path: &Path,
+    content: &str,
+    file_config: Option<&FileConfig>,
+) -> anyhow::Result<()> {
+    let strategy = file_config.map(|fc| fc.merge).unwrap_or_default();
+
+    if output_path.exists() && strategy != MergeStrategy::Overwrite {
+        let separator = file_config
+            .and_then(|fc| fc.merge_separator.clone())
+            .unwrap_or_else(|| "\n".to_string());
+
+        match strategy {
+            MergeStrategy::SkipIfExists => return Ok(()),
+            MergeStrategy::Append => {
+                let existing = fs::read_to_string(output_path)?;
+                fs::write(output_path, format!("{existing}{separator}{content}"))?;
+                return Ok(());
+            }
+            MergeStrategy::Prepend => {
+                let existing = fs::read_to_string(output_path)?;
+                fs::write(output_path, format!("{content}{separator}{existing}"))?;
+                return Ok(());
+            }
+            MergeStrategy::CargoManifest => {
+                let existing = fs::read_to_string(output_path)?;
+                fs::write(output_path, merge_cargo_manifest(&existing, content)?)?;
+                return Ok(());
+            }
+            MergeStrategy::Overwrite => {}
+        }
+    }
+
+    fs::write(output_path, content)?;
+    Ok(())
+}
+
+/// Splice the fuzz-related additions from a rendered template manifest into an
+/// existing `Cargo.toml`, preserving the user's keys and table ordering. New
+/// `[dependencies]`/`[dev-dependencies]` entries, `[[bin]]` targets, and
+/// `[profile.release]` tweaks are added; existing keys are never clobbered.
+/// The result is serialized with dependency tables ordered last.
+fn merge_cargo_manifest(existing: &str, rendered: &str) -> anyhow::Result<String> {
+    use toml_edit::{DocumentMut, Item, Table};
+
+    let mut doc: DocumentMut = existing.parse()?;
+    let incoming: DocumentMut = rendered.parse()?;
+
+    // Dependency-style tables: add only keys the user doesn't already have.
+    for table in ["dependencies", "dev-dependencies", "build-dependencies"] {
+        if let Some(src) = incoming.get(table).and_then(Item::as_table) {
+            let dst = doc
+                .entry(table)
+                .or_insert(Item::Table(Table::new()))
+                .as_table_mut()
+                .unwrap();
+            for (key, value) in src.iter() {
+                if !dst.contains_key(key) {
+                    dst.insert(key, value.clone());
+                }
+            }
+        }
+    }
+
+    // Append each [[bin]] harness target.
+    if let Some(src_bins) = incoming.get("bin").and_then(Item::as_array_of_tables) {
+        let dst = doc
+            .entry("bin")
+            .or_insert(Item::ArrayOfTables(Default::default()))
+            .as_array_of_tables_mut()
+            .unwrap();
+        for bin in src_bins.iter() {
+            dst.push(bin.clone());
+        }
+    }
+
+    // Merge [profile.release] tweaks without overwriting the user's keys.
+    if let Some(src_release) = incoming
+        .get("profile")
+        .and_then(|p| p.get("release"))
+        .and_then(Item::as_table)
+    {
+        let profile = doc
+            .entry("profile")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_mut()
+            .unwrap();
+        profile.set_implicit(true);
+        let release = profile
+            .entry("release")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_mut()
+            .unwrap();
+        for (key, value) in src_release.iter() {
+            if !release.contains_key(key) {
+                release.insert(key, value.clone());
+            }
+        }
+    }
+
+    // Conventional layout: dependency tables come last.
+    let dep_tables = ["dependencies", "dev-dependencies", "build-dependencies"];
+    let mut position = 0;
+    for (key, item) in doc.iter_mut() {
+        if !dep_tables.contains(&key.get()) {
+            if let Some(table) = item.as_table_mut() {
+                table.set_position(position);
+                position += 1;
+            }
+        }
+    }
+    for table in dep_tables {
+        if let Some(table) = doc.get_mut(table).and_then(Item::as_table_mut) {
+            table.set_position(position);
+            position += 1;
+        }
+    }
+
+    Ok(doc.to_string())
+}
+
 fn get_file_config<'a>(
     metadata: Option<&'a TemplateMetadata>,
     relative_path: &str,
@@ -361,9 +675,71 @@ fn should_include_by_convention(
         }
     }
 
+    // Glob-based ignore list (with `!` re-includes), applied last-match-wins.
+    let mut ignored = false;
+    for pattern in &conventions.ignore {
+        if let Some(negated) = pattern.strip_prefix('!') {
+            if path_matches_glob(negated, relative_path) {
+                ignored = false;
+            }
+        } else if path_matches_glob(pattern, relative_path) {
+            ignored = true;
+        }
+    }
+    if ignored {
+        return false;
+    }
+
+    // If include patterns are declared, the path must match at least one.
+    if !conventions.include.is_empty()
+        && !conventions
+            .include
+            .iter()
+            .any(|pattern| path_matches_glob(pattern, relative_path))
+    {
+        return false;
+    }
+
     true // Include by default
 }
 
+/// Match a relative path against a single glob pattern, compiled on demand.
+fn path_matches_glob(pattern: &str, relative_path: &str) -> bool {
+    glob::Pattern::new(pattern)
+        .map(|compiled| compiled.matches(relative_path))
+        .unwrap_or(false)
+}
+
+/// The longest leading non-wildcard segment of a glob, used to decide whether a
+/// directory is worth descending into.
+fn glob_base(pattern: &str) -> &str {
+    match pattern.find(['*', '?', '[', '!']) {
+        Some(idx) => {
+            let head = &pattern[..idx];
+            match head.rfind('/') {
+                Some(slash) => &head[..slash],
+                None => "",
+            }
+        }
+        None => pattern,
+    }
+}
+
+/// Whether any include pattern could still match something beneath `dir_path`.
+/// Used to short-circuit entire subtrees during the walk.
+fn directory_prefix_can_match(conventions: &FileConventions, dir_path: &str) -> bool {
+    if conventions.include.is_empty() {
+        return true;
+    }
+    conventions.include.iter().any(|pattern| {
+        let base = glob_base(pattern);
+        base.is_empty()
+            || dir_path.is_empty()
+            || base.starts_with(dir_path)
+            || dir_path.starts_with(base)
+    })
+}
+
 // Evaluate condition using Handlebars built-in helpers
 fn evaluate_condition(condition: &str, data: &serde_json::Value) -> bool {
     let handlebars = setup_handlebars();
@@ -444,6 +820,300 @@ fn set_executable(path: &Path) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// An abstract, read-only template tree. `FilesystemTree` is its one
+/// implementation: embedded templates (in release builds) and archive/stdin
+/// bundles are first materialized to disk and walked through it, so the
+/// generation pipeline can be driven from any source.
+pub trait TemplateTree {
+    /// List the direct children of the directory at `rel_path` (`""` = root).
+    fn list(&self, rel_path: &str) -> anyhow::Result<Vec<TreeEntry>>;
+    /// Read the raw bytes of the file at `rel_path`.
+    fn read(&self, rel_path: &str) -> anyhow::Result<Vec<u8>>;
+}
+
+pub struct TreeEntry {
+    pub name: String,
+    pub is_dir: bool,
+}
+
+/// Filesystem-backed template tree rooted at a directory.
+pub struct FilesystemTree {
+    root: PathBuf,
+}
+
+impl FilesystemTree {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl TemplateTree for FilesystemTree {
+    fn list(&self, rel_path: &str) -> anyhow::Result<Vec<TreeEntry>> {
+        let dir = if rel_path.is_empty() {
+            self.root.clone()
+        } else {
+            self.root.join(rel_path)
+        };
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            entries.push(TreeEntry {
+                name: entry.file_name().to_string_lossy().to_string(),
+                is_dir: entry.file_type()?.is_dir(),
+            });
+        }
+        Ok(entries)
+    }
+
+    fn read(&self, rel_path: &str) -> anyhow::Result<Vec<u8>> {
+        Ok(fs::read(self.root.join(rel_path))?)
+    }
+}
+
+/// Library entry point: generate a project from any template source, returning
+/// a [`GenerationReport`] describing the files created, the files skipped (with
+/// the condition that excluded them), and the hooks that would run.
+pub fn generate(
+    source: TemplateSource,
+    output_dir: &Path,
+    data: &serde_json::Value,
+    options: GenerationOptions,
+) -> anyhow::Result<GenerationReport> {
+    let handlebars = setup_handlebars();
+
+    // `options.minimal` turns on minimal mode even when `data` leaves it unset.
+    let mut data = data.clone();
+    if options.minimal {
+        if let Some(object) = data.as_object_mut() {
+            object.insert("minimal".to_string(), serde_json::Value::Bool(true));
+        }
+    }
+    let data = &data;
+
+    // Materialize the source into a concrete on-disk root. The TempDir (when
+    // present) must outlive the walk below.
+    let (root, _tempdir) = materialize_source(&source)?;
+    let metadata = load_template_metadata_from_path(&root)?;
+
+    let tree = FilesystemTree::new(&root);
+    let mut report = GenerationReport::default();
+    process_tree_recursive(
+        &tree,
+        "",
+        output_dir,
+        &handlebars,
+        data,
+        metadata.as_ref(),
+        &mut report,
+    )?;
+
+    if let Some(meta) = &metadata {
+        if let Some(hooks) = &meta.hooks.post_generate {
+            report.hooks.extend(hooks.iter().cloned());
+        }
+    }
+
+    Ok(report)
+}
+
+/// Resolve a [`TemplateSource`] into an on-disk template root, extracting
+/// archive/stdin bundles into a temporary directory that is returned so the
+/// caller can keep it alive for the duration of the walk.
+fn materialize_source(
+    source: &TemplateSource,
+) -> anyhow::Result<(PathBuf, Option<tempfile::TempDir>)> {
+    match source {
+        TemplateSource::Local(name) => {
+            if let Some(path) = resolve_external_template(name) {
+                Ok((path, None))
+            } else {
+                #[cfg(debug_assertions)]
+                {
+                    Ok((Path::new(TEMPLATES_PATH).join(name), None))
+                }
+                #[cfg(not(debug_assertions))]
+                {
+                    // Spill the embedded template to a temp dir so all sources
+                    // share the filesystem walker.
+                    let temp = tempfile::TempDir::new()?;
+                    let dir = TEMPLATES_DIR
+                        .get_dir(name)
+                        .ok_or_else(|| anyhow::anyhow!("Template '{}' not found", name))?;
+                    dir.extract(temp.path())?;
+                    let root = temp.path().join(name);
+                    Ok((root, Some(temp)))
+                }
+            }
+        }
+        TemplateSource::Archive(path) => {
+            let temp = tempfile::TempDir::new()?;
+            extract_archive(path, temp.path())?;
+            let root = single_root(temp.path())?;
+            Ok((root, Some(temp)))
+        }
+        TemplateSource::Stdin => {
+            use std::io::Read;
+            let temp = tempfile::TempDir::new()?;
+            let mut bytes = Vec::new();
+            std::io::stdin().read_to_end(&mut bytes)?;
+            let archive_path = temp.path().join("bundle");
+            fs::write(&archive_path, bytes)?;
+            extract_archive(&archive_path, temp.path())?;
+            fs::remove_file(&archive_path).ok();
+            let root = single_root(temp.path())?;
+            Ok((root, Some(temp)))
+        }
+        TemplateSource::GitHubFull(_) => {
+            anyhow::bail!("GitHub template sources must be fetched before calling generate()")
+        }
+    }
+}
+
+/// Extract a `.zip` or `.tar`/`.tar.gz` bundle into `dest`.
+fn extract_archive(path: &Path, dest: &Path) -> anyhow::Result<()> {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    if name.ends_with(".zip") {
+        let file = fs::File::open(path)?;
+        let mut zip = zip::ZipArchive::new(file)?;
+        zip.extract(dest)?;
+    } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+        let file = fs::File::open(path)?;
+        let decoder = flate2::read::GzDecoder::new(file);
+        tar::Archive::new(decoder).unpack(dest)?;
+    } else {
+        // Probe: try zip first (magic-number based), fall back to tar.
+        if let Ok(file) = fs::File::open(path) {
+            if let Ok(mut zip) = zip::ZipArchive::new(file) {
+                zip.extract(dest)?;
+                return Ok(());
+            }
+        }
+        let file = fs::File::open(path)?;
+        tar::Archive::new(file).unpack(dest)?;
+    }
+    Ok(())
+}
+
+/// If `dir` contains exactly one subdirectory (the bundle's top folder), return
+/// it; otherwise treat `dir` itself as the template root.
+fn single_root(dir: &Path) -> anyhow::Result<PathBuf> {
+    let mut subdirs = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            subdirs.push(entry.path());
+        }
+    }
+    if subdirs.len() == 1 {
+        Ok(subdirs.pop().unwrap())
+    } else {
+        Ok(dir.to_path_buf())
+    }
+}
+
+/// Walk a [`TemplateTree`], rendering files into `output_dir` and recording the
+/// outcome in `report`. Shares the convention, condition and merge logic with
+/// the concrete backends.
+fn process_tree_recursive(
+    tree: &dyn TemplateTree,
+    relative_path: &str,
+    output_dir: &Path,
+    handlebars: &Handlebars,
+    data: &serde_json::Value,
+    metadata: Option<&TemplateMetadata>,
+    report: &mut GenerationReport,
+) -> anyhow::Result<()> {
+    fs::create_dir_all(output_dir)?;
+
+    for entry in tree.list(relative_path)? {
+        let current_relative_path = if relative_path.is_empty() {
+            entry.name.clone()
+        } else {
+            format!("{relative_path}/{}", entry.name)
+        };
+
+        if entry.is_dir {
+            if let Some(metadata) = metadata {
+                let is_minimal = data
+                    .get("minimal")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                if is_minimal
+                    && relative_path.is_empty()
+                    && metadata
+                        .file_conventions
+                        .full_mode_only
+                        .contains(&entry.name)
+                {
+                    continue;
+                }
+                if !directory_prefix_can_match(&metadata.file_conventions, &current_relative_path) {
+                    continue;
+                }
+            }
+
+            let output_dirname = handlebars.render_template(&entry.name, data)?;
+            process_tree_recursive(
+                tree,
+                &current_relative_path,
+                &output_dir.join(&output_dirname),
+                handlebars,
+                data,
+                metadata,
+                report,
+            )?;
+            continue;
+        }
+
+        if entry.name == "template.toml" {
+            continue;
+        }
+
+        if should_skip_file(metadata, &current_relative_path, data) {
+            let condition = get_file_config(metadata, &current_relative_path)
+                .and_then(|fc| fc.condition().cloned());
+            report.skipped.push(SkippedFile {
+                path: current_relative_path,
+                condition,
+            });
+            continue;
+        }
+
+        let file_config = get_file_config(metadata, &current_relative_path);
+        let should_template = file_config.is_none_or(|fc| fc.should_template());
+
+        let output_filename = if should_template {
+            handlebars.render_template(&entry.name, data)?
+        } else {
+            entry.name.clone()
+        };
+        let output_path = output_dir.join(&output_filename);
+
+        let bytes = tree.read(&current_relative_path)?;
+        if let Ok(text) = String::from_utf8(bytes.clone()) {
+            if should_template {
+                let rendered = handlebars.render_template(&text, data)?;
+                if rendered.trim().is_empty() {
+                    continue;
+                }
+                write_with_merge(&output_path, &rendered, file_config)?;
+            } else {
+                write_with_merge(&output_path, &text, file_config)?;
+            }
+        } else {
+            fs::write(&output_path, bytes)?;
+        }
+
+        if file_config.is_some_and(|fc| fc.is_executable()) {
+            set_executable(&output_path)?;
+        }
+
+        report.created.push(current_relative_path);
+    }
+
+    Ok(())
+}
+
 pub fn process_filesystem_template_directory(
     template_path: &Path,
     output_dir: &Path,
@@ -503,6 +1173,11 @@ fn process_filesystem_directory_recursive(
                         continue;
                     }
                 }
+
+                // Short-circuit subtrees that no include glob can ever match.
+                if !directory_prefix_can_match(&metadata.file_conventions, &current_relative_path) {
+                    continue;
+                }
             }
 
             // Template the directory name if needed
@@ -552,9 +1227,9 @@ fn process_filesystem_directory_recursive(
                     if rendered.trim().is_empty() {
                         continue;
                     }
-                    fs::write(&output_path, rendered)?;
+                    write_with_merge(&output_path, &rendered, file_config)?;
                 } else {
-                    fs::write(&output_path, text_content)?;
+                    write_with_merge(&output_path, &text_content, file_config)?;
                 }
             } else {
                 // Binary file - write as-is
@@ -570,3 +1245,66 @@ fn process_filesystem_directory_recursive(
 
     Ok(())
 }
+
+/// Scaffold the seed corpus directory and dictionary stub for `target` under the
+/// generated project's `fuzz/` folder.
+///
+/// Creates `fuzz/corpus/<target>/`, copying every file from `seed_corpus` into
+/// it when one is supplied, and writes `fuzz/<target>.dict` - seeded from
+/// `dictionary` when given, otherwise a commented stub the user can extend.
+///
+/// When `fuzzer_options` is non-empty, the accumulated `key=value` options are
+/// also written to `fuzz/<target>.options` in the libFuzzer `[libfuzzer]`
+/// options-file format; the oss-fuzz build integration stages this file next to
+/// each binary, and it can be passed to a bare libFuzzer run directly.
+pub fn scaffold_corpus_and_dictionary(
+    out_path: &Path,
+    target: &str,
+    seed_corpus: Option<&Path>,
+    dictionary: Option<&Path>,
+    fuzzer_options: &[String],
+) -> anyhow::Result<()> {
+    let fuzz_dir = out_path.join("fuzz");
+    let corpus_dir = fuzz_dir.join("corpus").join(target);
+    fs::create_dir_all(&corpus_dir)?;
+
+    if let Some(seed_dir) = seed_corpus {
+        for entry in fs::read_dir(seed_dir).map_err(|e| {
+            anyhow::anyhow!("Failed to read seed corpus '{}': {}", seed_dir.display(), e)
+        })? {
+            let entry = entry?;
+            if entry.file_type()?.is_file() {
+                fs::copy(entry.path(), corpus_dir.join(entry.file_name()))?;
+            }
+        }
+    }
+
+    let dict_path = fuzz_dir.join(format!("{target}.dict"));
+    if let Some(dict) = dictionary {
+        fs::copy(dict, &dict_path).map_err(|e| {
+            anyhow::anyhow!("Failed to copy dictionary '{}': {}", dict.display(), e)
+        })?;
+    } else {
+        fs::write(
+            &dict_path,
+            format!(
+                "# libFuzzer dictionary for {target}.\n\
+                 # Add keywords the target expects, one per line, e.g.:\n\
+                 #   \"GET\"\n\
+                 #   keyword=\"value\"\n"
+            ),
+        )?;
+    }
+
+    if !fuzzer_options.is_empty() {
+        let options_path = fuzz_dir.join(format!("{target}.options"));
+        let mut body = String::from("[libfuzzer]\n");
+        for option in fuzzer_options {
+            let (key, value) = option.split_once('=').unwrap_or((option, ""));
+            body.push_str(&format!("{key} = {value}\n"));
+        }
+        fs::write(&options_path, body)?;
+    }
+
+    Ok(())
+}
@@ -1,12 +1,68 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// User-level configuration loaded from `fuzz-init.toml`, allowing teams to
+/// point the tool at private template collections without forking the crate.
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct UserConfig {
+    /// Additional template search roots, scanned in order. Later roots override
+    /// earlier roots (and the built-in set) when template names collide.
+    #[serde(default)]
+    pub template_dirs: Vec<PathBuf>,
+    /// Partial aliases: `alias = "actual/partial/name"`. One map for all roots;
+    /// it is read from the file, but partial registration does not consult it.
+    #[serde(default)]
+    pub partial_aliases: HashMap<String, String>,
+    /// Default selections applied when the corresponding flag is omitted.
+    #[serde(default)]
+    pub defaults: ConfigDefaults,
+    /// Named presets, e.g. `[favorites.myorg]`, bundling a template source and
+    /// option set that `--favorite <NAME>` expands as if typed on the CLI.
+    #[serde(default)]
+    pub favorites: HashMap<String, Favorite>,
+}
+
+/// Baseline option values from the user config, used to seed any flag the user
+/// did not pass explicitly.
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct ConfigDefaults {
+    pub language: Option<String>,
+    /// Default fuzzer engine(s); several may be listed to seed a multi-engine
+    /// selection, matching the repeatable `--fuzzer` flag.
+    #[serde(default)]
+    pub fuzzer: Vec<String>,
+    pub integration: Option<String>,
+    #[serde(default)]
+    pub minimal: bool,
+}
+
+/// A named scaffolding preset selected with `--favorite`.
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct Favorite {
+    /// A remote template source, e.g. `github:org/repo` (mutually exclusive with
+    /// `language`, like the CLI flags it stands in for).
+    pub template: Option<String>,
+    pub language: Option<String>,
+    /// Fuzzer engine(s) this favorite selects, matching the repeatable
+    /// `--fuzzer` flag.
+    #[serde(default)]
+    pub fuzzer: Vec<String>,
+    pub integration: Option<String>,
+    #[serde(default)]
+    pub minimal: bool,
+}
 
 /// Tracks which values were provided via prompts vs. command-line flags
 #[derive(Debug, Default)]
 pub struct PromptedValues {
     pub project_name: bool,
     pub language: bool,
+    pub fuzzer: bool,
     pub integration: bool,
+    /// `KEY=VALUE` pairs for template variables that were prompted for, so the
+    /// reproducible CLI hint can append the matching `--define` flags.
+    pub defines: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -24,6 +80,21 @@ pub struct FuzzerConfig {
     pub options: Vec<FuzzerOption>,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SanitizerOption {
+    pub name: String,
+    pub display_name: String,
+    pub description: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SanitizerConfig {
+    pub supported: Vec<String>,
+    pub default: Vec<String>,
+    #[serde(default)]
+    pub options: Vec<SanitizerOption>,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct IntegrationOption {
     pub name: String,
@@ -52,6 +123,8 @@ pub struct TemplateMetadata {
     #[serde(default)]
     pub integrations: Option<IntegrationConfig>,
     #[serde(default)]
+    pub sanitizers: Option<SanitizerConfig>,
+    #[serde(default)]
     pub file_conventions: FileConventions,
     #[serde(default)]
     pub validation: Option<ValidationConfig>,
@@ -66,12 +139,24 @@ pub struct TemplateInfo {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct VariableConfig {
+    /// `string` (the default) or `bool`.
+    #[serde(rename = "type", default)]
+    pub var_type: Option<String>,
+    /// Prompt shown interactively; falls back to the variable name.
+    #[serde(default)]
+    pub prompt: Option<String>,
     #[serde(default)]
     pub default: Option<String>,
     #[serde(default)]
     pub required: bool,
     #[serde(default)]
     pub description: String,
+    /// When present, the value is chosen from this list via a Select prompt.
+    #[serde(default)]
+    pub choices: Option<Vec<String>>,
+    /// Optional regular expression the (free-text) value must match.
+    #[serde(default)]
+    pub regex: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -83,6 +168,32 @@ pub struct FileConfig {
     pub template: bool,
     #[serde(default)]
     pub condition: Option<String>,
+    /// How to handle an output path that already exists.
+    #[serde(default)]
+    pub merge: MergeStrategy,
+    /// Separator inserted between existing and new content for `append`/`prepend`.
+    #[serde(default)]
+    pub merge_separator: Option<String>,
+}
+
+/// Strategy for reconciling a generated file with one that already exists on
+/// disk, used when integrating into an existing project.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeStrategy {
+    /// Replace the existing file (the default).
+    #[default]
+    Overwrite,
+    /// Append the rendered content to the existing file.
+    Append,
+    /// Prepend the rendered content to the existing file.
+    Prepend,
+    /// Leave the existing file untouched.
+    SkipIfExists,
+    /// Treat both files as Cargo manifests and splice in only the fuzz-related
+    /// additions, preserving the user's existing keys and table ordering.
+    #[serde(rename = "cargo-manifest")]
+    CargoManifest,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -103,6 +214,14 @@ pub struct FileConventions {
     pub always_include: Vec<String>,
     #[serde(default)]
     pub full_mode_only: Vec<String>,
+    /// Glob patterns a path must match to be kept (e.g. `**/*.md`, `tests/**`).
+    /// An empty list means "keep everything not otherwise ignored".
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Glob patterns whose matches are skipped. A leading `!` re-includes a
+    /// previously ignored path (e.g. `!fuzz/corpus/**`).
+    #[serde(default)]
+    pub ignore: Vec<String>,
     #[serde(default)]
     pub template_extensions: Vec<String>,
     #[serde(default)]
@@ -119,6 +238,34 @@ fn default_true() -> bool {
 pub enum TemplateSource {
     Local(String),
     GitHubFull(String),
+    /// A tar/zip bundle containing a single template tree.
+    Archive(PathBuf),
+    /// A template bundle piped in on standard input (tar/zip).
+    Stdin,
+}
+
+/// Options controlling a library-driven generation run.
+#[derive(Debug, Default)]
+pub struct GenerationOptions {
+    pub minimal: bool,
+}
+
+/// Structured result of a generation run, returned by [`generate`] so callers
+/// can inspect what happened instead of relying on side effects alone.
+///
+/// [`generate`]: crate::template_processor::generate
+#[derive(Debug, Default)]
+pub struct GenerationReport {
+    pub created: Vec<String>,
+    pub skipped: Vec<SkippedFile>,
+    pub hooks: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct SkippedFile {
+    pub path: String,
+    /// The condition that excluded the file, when one was responsible.
+    pub condition: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Default)]